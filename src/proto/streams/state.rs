@@ -54,8 +54,9 @@ pub struct State {
 #[derive(Debug, Clone, Copy)]
 enum Inner {
     Idle,
-    // TODO: these states shouldn't count against concurrency limits:
-    //ReservedLocal,
+    // Reserved states don't count against the peer's concurrency limits,
+    // see `is_reserved`.
+    ReservedLocal,
     ReservedRemote,
     Open { local: OpenPeer, remote: OpenPeer },
     HalfClosedLocal(OpenPeer), // TODO: explicitly name this value
@@ -87,6 +88,26 @@ enum Peer {
     Remote
 }
 
+/// Describes why a stream reached the `Closed` state.
+///
+/// This is exposed to upper layers so that, e.g., a sender that has already
+/// finished sending (via END_STREAM) can still observe whether the peer
+/// replied with a clean END_STREAM or reset the stream instead.
+#[derive(Debug, Copy, Clone)]
+pub enum CloseCause {
+    /// Both directions finished cleanly via END_STREAM.
+    EndStream,
+    /// The local endpoint reset the stream.
+    LocalReset(Reason),
+    /// The remote endpoint reset the stream.
+    RemoteReset(Reason),
+    /// The user dropped all handles to the stream without explicitly
+    /// canceling it.
+    Canceled,
+    /// The stream was closed due to an I/O error.
+    Io,
+}
+
 impl State {
     /// Opens the send-half of a stream if it is not already open.
     pub fn send_open(&mut self, eos: bool) -> Result<(), UserError> {
@@ -101,6 +122,15 @@ impl State {
                     remote: AwaitingHeaders,
                 }
             },
+            ReservedLocal => {
+                // The promised response stream is write-only from the
+                // local's perspective; the peer will not send HEADERS.
+                if eos {
+                    Closed(Cause::EndStream)
+                } else {
+                    HalfClosedRemote(local)
+                }
+            },
             Open {
                 local: AwaitingHeaders,
                 remote,
@@ -194,6 +224,17 @@ impl State {
         }
     }
 
+    /// Transition from Idle -> ReservedLocal
+    pub fn reserve_local(&mut self) -> Result<(), UserError> {
+        match self.inner {
+            Idle => {
+                self.inner = ReservedLocal;
+                Ok(())
+            },
+            _ => Err(UnexpectedFrameType),
+        }
+    }
+
     /// Indicates that the remote side will not send more data to the local.
     pub fn recv_close(&mut self) -> Result<(), RecvError> {
         match self.inner {
@@ -294,6 +335,35 @@ impl State {
         }
     }
 
+    /// Returns true if the remote explicitly reset this stream.
+    pub fn is_remote_reset(&self) -> bool {
+        match self.close_cause() {
+            Some(CloseCause::RemoteReset(_)) => true,
+            _ => false,
+        }
+    }
+
+    /// Returns the reason the remote reset this stream, if any.
+    pub fn reset_reason(&self) -> Option<Reason> {
+        match self.close_cause() {
+            Some(CloseCause::RemoteReset(reason)) => Some(reason),
+            _ => None,
+        }
+    }
+
+    /// Returns the reason the stream was closed, for informing callers that
+    /// have finished sending but still need to learn the peer's disposition.
+    pub fn close_cause(&self) -> Option<CloseCause> {
+        match self.inner {
+            Closed(Cause::EndStream) => Some(CloseCause::EndStream),
+            Closed(Cause::Proto(Peer::Local, reason)) => Some(CloseCause::LocalReset(reason)),
+            Closed(Cause::Proto(Peer::Remote, reason)) => Some(CloseCause::RemoteReset(reason)),
+            Closed(Cause::Canceled) => Some(CloseCause::Canceled),
+            Closed(Cause::Io) => Some(CloseCause::Io),
+            _ => None,
+        }
+    }
+
     /// Returns true if a stream is open or half-closed.
     pub fn is_at_least_half_open(&self) -> bool {
         match self.inner {
@@ -351,7 +421,16 @@ impl State {
 
     pub fn is_recv_closed(&self) -> bool {
         match self.inner {
-            Closed(..) | HalfClosedRemote(..) => true,
+            Closed(..) | HalfClosedRemote(..) | ReservedLocal => true,
+            _ => false,
+        }
+    }
+
+    /// Returns true if the stream is reserved in either direction and so
+    /// should not count against the peer's concurrency limits.
+    pub fn is_reserved(&self) -> bool {
+        match self.inner {
+            ReservedRemote | ReservedLocal => true,
             _ => false,
         }
     }
@@ -371,7 +450,7 @@ impl State {
             Closed(Cause::Proto(_, reason)) => Err(proto::Error::Proto(reason)),
             Closed(Cause::Canceled) => Err(proto::Error::Proto(Reason::CANCEL)),
             Closed(Cause::Io) => Err(proto::Error::Io(io::ErrorKind::BrokenPipe.into())),
-            Closed(Cause::EndStream) | HalfClosedRemote(..) => Ok(false),
+            Closed(Cause::EndStream) | HalfClosedRemote(..) | ReservedLocal => Ok(false),
             _ => Ok(true),
         }
     }